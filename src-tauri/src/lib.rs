@@ -1,5 +1,112 @@
-// unused import removed
-use tokio_postgres::NoTls;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use base64::Engine;
+use deadpool_postgres::{Manager, ManagerConfig, Object, Pool, RecyclingMethod};
+use tokio_postgres::config::SslMode;
+use tokio_postgres::{Config, NoTls};
+
+/// Optional TLS material for connecting to servers that require encryption.
+///
+/// The two certificate fields are base64-encoded so they can be passed
+/// unchanged from the frontend: `ca_cert` is a CA certificate in PEM form and
+/// `client_identity` is a PKCS#12 bundle (certificate + private key).
+/// `client_identity_password` is the bundle's passphrase as plain text.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct TlsOptions {
+    ca_cert: Option<String>,
+    client_identity: Option<String>,
+    client_identity_password: Option<String>,
+}
+
+/// Shared state holding one connection pool per connection string and TLS
+/// configuration.
+///
+/// Opening a fresh TCP connection (and spawning its driver task) on every
+/// command is a full auth round trip per UI interaction, so pools are created
+/// lazily, keyed by `pool_key`, and reused across commands.
+#[derive(Default)]
+pub struct DbState {
+    pools: Mutex<HashMap<String, Pool>>,
+}
+
+/// Build the `native-tls` connector from the optional base64-encoded CA
+/// certificate and PKCS#12 client identity supplied by the caller.
+fn build_tls_connector(tls: &TlsOptions) -> Result<postgres_native_tls::MakeTlsConnector, String> {
+    let mut builder = native_tls::TlsConnector::builder();
+    if let Some(ca) = &tls.ca_cert {
+        let pem = base64::engine::general_purpose::STANDARD
+            .decode(ca)
+            .map_err(|e| format!("invalid base64 CA certificate: {}", e))?;
+        let cert = native_tls::Certificate::from_pem(&pem).map_err(|e| e.to_string())?;
+        builder.add_root_certificate(cert);
+    }
+    if let Some(identity) = &tls.client_identity {
+        let der = base64::engine::general_purpose::STANDARD
+            .decode(identity)
+            .map_err(|e| format!("invalid base64 client identity: {}", e))?;
+        let password = tls.client_identity_password.as_deref().unwrap_or("");
+        let identity = native_tls::Identity::from_pkcs12(&der, password).map_err(|e| e.to_string())?;
+        builder.identity(identity);
+    }
+    let connector = builder.build().map_err(|e| e.to_string())?;
+    Ok(postgres_native_tls::MakeTlsConnector::new(connector))
+}
+
+/// Create a pool for `connection_string`, picking `NoTls` or a `native-tls`
+/// connector based on the `sslmode` carried by the string.
+fn build_pool(connection_string: &str, tls: &TlsOptions) -> Result<Pool, String> {
+    let config: Config = connection_string
+        .parse()
+        .map_err(|e: tokio_postgres::Error| e.to_string())?;
+    let mgr_config = ManagerConfig {
+        recycling_method: RecyclingMethod::Fast,
+    };
+
+    let manager = if config.get_ssl_mode() == SslMode::Disable {
+        Manager::from_config(config, NoTls, mgr_config)
+    } else {
+        Manager::from_config(config, build_tls_connector(tls)?, mgr_config)
+    };
+
+    Pool::builder(manager).build().map_err(|e| e.to_string())
+}
+
+/// Cache key for a pool: the connection string plus its TLS material, so that
+/// two callers of the same server with different CA/client certs get distinct
+/// pools instead of silently reusing the first caller's TLS config.
+fn pool_key(connection_string: &str, tls: &TlsOptions) -> String {
+    format!(
+        "{}|{}|{}|{}",
+        connection_string,
+        tls.ca_cert.as_deref().unwrap_or(""),
+        tls.client_identity.as_deref().unwrap_or(""),
+        tls.client_identity_password.as_deref().unwrap_or(""),
+    )
+}
+
+/// Pull a pooled client for `connection_string`, creating the pool on first
+/// use. Pools are cheap to clone (`Arc` internally) so the lock is released
+/// before the `await` that actually checks a client out.
+async fn get_client(
+    state: &tauri::State<'_, DbState>,
+    connection_string: &str,
+    tls: &TlsOptions,
+) -> Result<Object, String> {
+    let key = pool_key(connection_string, tls);
+    let pool = {
+        let mut pools = state.pools.lock().map_err(|e| e.to_string())?;
+        match pools.get(&key) {
+            Some(pool) => pool.clone(),
+            None => {
+                let pool = build_pool(connection_string, tls)?;
+                pools.insert(key, pool.clone());
+                pool
+            }
+        }
+    };
+    pool.get().await.map_err(|e| e.to_string())
+}
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
@@ -8,18 +115,12 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-async fn test_connection(connection_string: String) -> Result<String, String> {
-    let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    // The connection object performs the actual communication with the database,
-    // so spawn it off to run on its own.
-    tokio::spawn(async move {
-        if let Err(e) = connection.await {
-            eprintln!("connection error: {}", e);
-        }
-    });
+async fn test_connection(
+    connection_string: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<String, String> {
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default()).await?;
 
     let rows = client
         .query("SELECT version()", &[])
@@ -31,16 +132,13 @@ async fn test_connection(connection_string: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn execute_sql(connection_string: String, sql: String) -> Result<String, String> {
-    let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    tokio::spawn(async move {
-        if let Err(e) = connection.await {
-            eprintln!("connection error: {}", e);
-        }
-    });
+async fn execute_sql(
+    connection_string: String,
+    sql: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<String, String> {
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default()).await?;
 
     client
         .batch_execute(&sql)
@@ -51,7 +149,11 @@ async fn execute_sql(connection_string: String, sql: String) -> Result<String, S
 }
 
 #[tauri::command]
-async fn get_tables(connection_string: String) -> Result<Vec<String>, String> {
+async fn get_tables(
+    connection_string: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<Vec<String>, String> {
     // Log connection attempt (hide password for security)
     let masked_conn_str = if connection_string.contains("://") {
         let parts: Vec<&str> = connection_string.split("://").collect();
@@ -74,21 +176,15 @@ async fn get_tables(connection_string: String) -> Result<Vec<String>, String> {
         masked_conn_str
     );
 
-    let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default())
         .await
         .map_err(|e| {
             eprintln!("[Rust get_tables] Connection failed: {}", e);
-            e.to_string()
+            e
         })?;
 
     println!("[Rust get_tables] Connection established successfully");
 
-    tokio::spawn(async move {
-        if let Err(e) = connection.await {
-            eprintln!("[Rust get_tables] Connection error: {}", e);
-        }
-    });
-
     // First, let's see what's actually in the database
     println!("[Rust get_tables] Running diagnostic query to see all tables...");
     let diagnostic_rows = client
@@ -150,48 +246,39 @@ async fn get_tables(connection_string: String) -> Result<Vec<String>, String> {
 pub struct ColumnInfo {
     name: String,
     data_type: String,
+    formatted_type: String, // New: fully-qualified type from format_type (length/precision, arrays, user types)
     is_nullable: bool,
     column_default: Option<String>,
     is_auto_generated: bool,
     is_generated: bool, // New: GENERATED ALWAYS AS ... STORED
-    is_identity: bool,  // New: GENERATED ALWAYS AS IDENTITY
+    generation_expression: Option<String>, // New: the expression behind a generated column
+    is_identity: bool,  // New: GENERATED ALWAYS / BY DEFAULT AS IDENTITY
+    identity_generation: Option<String>, // New: 'ALWAYS' or 'BY DEFAULT' for identity columns
     is_primary_key: bool,
     is_foreign_key: bool,
     foreign_key_table: Option<String>,
     foreign_key_column: Option<String>,
 }
 
-#[tauri::command]
-async fn get_columns(
-    connection_string: String,
-    table_name: String,
+/// Fetch comprehensive column metadata (types, nullability, defaults,
+/// generated/identity flags, primary and foreign keys) for a single table.
+///
+/// Shared by the `get_columns` command and by `export_dump`, which rebuilds
+/// `CREATE TABLE` statements from the same metadata.
+async fn fetch_columns(
+    client: &tokio_postgres::Client,
+    table_name: &str,
 ) -> Result<Vec<ColumnInfo>, String> {
-    println!(
-        "[Rust get_columns] Fetching columns for table: {}",
-        table_name
-    );
-
-    let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
-        .await
-        .map_err(|e| {
-            eprintln!("[Rust get_columns] Connection failed: {}", e);
-            e.to_string()
-        })?;
-
-    tokio::spawn(async move {
-        if let Err(e) = connection.await {
-            eprintln!("[Rust get_columns] Connection error: {}", e);
-        }
-    });
-
     // Query to get comprehensive column information including foreign keys
     let query = r#"
-        SELECT 
+        SELECT
             c.column_name,
             c.data_type,
+            ft.formatted_type,
             c.is_nullable,
             c.column_default,
-            CASE 
+            c.generation_expression,
+            CASE
                 WHEN c.column_default LIKE 'nextval%' THEN true
                 WHEN c.column_default LIKE '%auto_increment%' THEN true
                 ELSE false
@@ -204,7 +291,8 @@ async fn get_columns(
                 WHEN c.is_identity = 'YES' THEN true
                 ELSE false
             END as is_identity,
-            CASE 
+            c.identity_generation,
+            CASE
                 WHEN pk.column_name IS NOT NULL THEN true
                 ELSE false
             END as is_primary_key,
@@ -215,6 +303,17 @@ async fn get_columns(
             fk.foreign_table_name,
             fk.foreign_column_name
         FROM information_schema.columns c
+        LEFT JOIN (
+            SELECT a.attname,
+                   format_type(a.atttypid, a.atttypmod) AS formatted_type
+            FROM pg_catalog.pg_attribute a
+            JOIN pg_catalog.pg_class cl ON cl.oid = a.attrelid
+            JOIN pg_catalog.pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE cl.relname = $1
+                AND ns.nspname = 'public'
+                AND a.attnum > 0
+                AND NOT a.attisdropped
+        ) ft ON ft.attname = c.column_name
         LEFT JOIN (
             SELECT ku.column_name
             FROM information_schema.table_constraints tc
@@ -228,15 +327,19 @@ async fn get_columns(
         LEFT JOIN (
             SELECT
                 kcu.column_name,
-                ccu.table_name AS foreign_table_name,
-                ccu.column_name AS foreign_column_name
+                rcu.table_name AS foreign_table_name,
+                rcu.column_name AS foreign_column_name
             FROM information_schema.table_constraints AS tc
             JOIN information_schema.key_column_usage AS kcu
                 ON tc.constraint_name = kcu.constraint_name
                 AND tc.table_schema = kcu.table_schema
-            JOIN information_schema.constraint_column_usage AS ccu
-                ON ccu.constraint_name = tc.constraint_name
-                AND ccu.table_schema = tc.table_schema
+            JOIN information_schema.referential_constraints AS rc
+                ON rc.constraint_name = tc.constraint_name
+                AND rc.constraint_schema = tc.table_schema
+            JOIN information_schema.key_column_usage AS rcu
+                ON rcu.constraint_name = rc.unique_constraint_name
+                AND rcu.constraint_schema = rc.unique_constraint_schema
+                AND rcu.ordinal_position = kcu.position_in_unique_constraint
             WHERE tc.constraint_type = 'FOREIGN KEY'
                 AND tc.table_name = $1
                 AND tc.table_schema = 'public'
@@ -256,11 +359,16 @@ async fn get_columns(
         .map(|row| ColumnInfo {
             name: row.get("column_name"),
             data_type: row.get("data_type"),
+            formatted_type: row
+                .get::<_, Option<String>>("formatted_type")
+                .unwrap_or_else(|| row.get("data_type")),
             is_nullable: row.get::<_, String>("is_nullable") == "YES",
             column_default: row.get("column_default"),
             is_auto_generated: row.get("is_auto_generated"),
             is_generated: row.get("is_generated"),
+            generation_expression: row.get("generation_expression"),
             is_identity: row.get("is_identity"),
+            identity_generation: row.get("identity_generation"),
             is_primary_key: row.get("is_primary_key"),
             is_foreign_key: row.get("is_foreign_key"),
             foreign_key_table: row.get("foreign_table_name"),
@@ -268,6 +376,30 @@ async fn get_columns(
         })
         .collect();
 
+    Ok(columns)
+}
+
+#[tauri::command]
+async fn get_columns(
+    connection_string: String,
+    table_name: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<Vec<ColumnInfo>, String> {
+    println!(
+        "[Rust get_columns] Fetching columns for table: {}",
+        table_name
+    );
+
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default())
+        .await
+        .map_err(|e| {
+            eprintln!("[Rust get_columns] Connection failed: {}", e);
+            e
+        })?;
+
+    let columns = fetch_columns(&client, &table_name).await?;
+
     println!(
         "[Rust get_columns] Found {} columns for table '{}'",
         columns.len(),
@@ -281,22 +413,18 @@ async fn get_columns(
 async fn execute_query(
     connection_string: String,
     query: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
 ) -> Result<Vec<serde_json::Value>, String> {
     println!("[Rust execute_query] Executing query");
 
-    let (client, connection) = tokio_postgres::connect(&connection_string, NoTls)
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default())
         .await
         .map_err(|e| {
             eprintln!("[Rust execute_query] Connection failed: {}", e);
-            e.to_string()
+            e
         })?;
 
-    tokio::spawn(async move {
-        if let Err(e) = connection.await {
-            eprintln!("[Rust execute_query] Connection error: {}", e);
-        }
-    });
-
     let rows = client.query(&query, &[]).await.map_err(|e| {
         eprintln!("[Rust execute_query] Query failed: {}", e);
         e.to_string()
@@ -307,28 +435,7 @@ async fn execute_query(
     for row in rows.iter() {
         let mut obj = serde_json::Map::new();
         for (idx, column) in row.columns().iter().enumerate() {
-            let name = column.name();
-            let value: serde_json::Value = match column.type_().name() {
-                "int4" | "int8" | "int2" => row
-                    .try_get::<_, i64>(idx)
-                    .map(|v| serde_json::Value::Number(v.into()))
-                    .unwrap_or(serde_json::Value::Null),
-                "text" | "varchar" | "bpchar" => row
-                    .try_get::<_, String>(idx)
-                    .map(serde_json::Value::String)
-                    .unwrap_or(serde_json::Value::Null),
-                "bool" => row
-                    .try_get::<_, bool>(idx)
-                    .map(serde_json::Value::Bool)
-                    .unwrap_or(serde_json::Value::Null),
-                _ => {
-                    // Try to get as string for other types
-                    row.try_get::<_, String>(idx)
-                        .map(serde_json::Value::String)
-                        .unwrap_or(serde_json::Value::Null)
-                }
-            };
-            obj.insert(name.to_string(), value);
+            obj.insert(column.name().to_string(), pg_value_to_json(row, idx, column.type_()));
         }
         results.push(serde_json::Value::Object(obj));
     }
@@ -337,17 +444,731 @@ async fn execute_query(
     Ok(results)
 }
 
+/// A Postgres `numeric` decoded straight to its exact decimal string.
+///
+/// Decoding through `rust_decimal::Decimal` caps at ~28–29 significant digits
+/// and errors on anything larger (`numeric(40,0)`, `1e50`), which surfaced as a
+/// silent `NULL` — the very regression this converter exists to remove. Reading
+/// the binary wire format to text instead preserves arbitrary precision.
+struct PgNumericText(String);
+
+impl<'a> tokio_postgres::types::FromSql<'a> for PgNumericText {
+    fn from_sql(
+        _ty: &tokio_postgres::types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(PgNumericText(decode_pg_numeric(raw)?))
+    }
+
+    fn accepts(ty: &tokio_postgres::types::Type) -> bool {
+        *ty == tokio_postgres::types::Type::NUMERIC
+    }
+}
+
+/// Decode Postgres' binary `numeric` representation into a decimal string.
+///
+/// The wire format is a header (`ndigits`, `weight`, `sign`, `dscale`) followed
+/// by `ndigits` base-10000 groups; group `i` carries the factor `10000^(weight
+/// - i)`. We reconstruct the integer and fractional parts from those positions.
+fn decode_pg_numeric(raw: &[u8]) -> Result<String, Box<dyn std::error::Error + Sync + Send>> {
+    if raw.len() < 8 {
+        return Err("numeric: header too short".into());
+    }
+    let read_i16 = |offset: usize| i16::from_be_bytes([raw[offset], raw[offset + 1]]);
+    let ndigits = read_i16(0) as usize;
+    let weight = read_i16(2) as i32;
+    let sign = read_i16(4) as u16;
+    let dscale = read_i16(6).max(0) as usize;
+
+    if sign == 0xC000 {
+        return Ok("NaN".to_string());
+    }
+
+    let mut groups = Vec::with_capacity(ndigits);
+    for i in 0..ndigits {
+        let offset = 8 + i * 2;
+        if offset + 2 > raw.len() {
+            return Err("numeric: digit group out of range".into());
+        }
+        groups.push(read_i16(offset));
+    }
+
+    // The group carrying factor 10000^position, or zero when absent.
+    let group_at = |position: i32| -> i16 {
+        let index = weight - position;
+        if index >= 0 && (index as usize) < groups.len() {
+            groups[index as usize]
+        } else {
+            0
+        }
+    };
+
+    let mut integer = String::new();
+    for position in (0..=weight.max(0)).rev() {
+        let group = group_at(position);
+        if integer.is_empty() {
+            integer.push_str(&group.to_string());
+        } else {
+            integer.push_str(&format!("{:04}", group));
+        }
+    }
+    if integer.is_empty() {
+        integer.push('0');
+    }
+
+    let mut fraction = String::new();
+    let mut position = -1;
+    while fraction.len() < dscale {
+        fraction.push_str(&format!("{:04}", group_at(position)));
+        position -= 1;
+    }
+    fraction.truncate(dscale);
+
+    let mut out = String::new();
+    if sign == 0x4000 {
+        out.push('-');
+    }
+    out.push_str(&integer);
+    if dscale > 0 {
+        out.push('.');
+        out.push_str(&fraction);
+    }
+    Ok(out)
+}
+
+/// Build a JSON array from a one-dimensional Postgres array, mapping each
+/// element with `f` and turning SQL `NULL` elements into JSON `null`.
+fn array_to_json<T>(
+    result: Result<Vec<Option<T>>, tokio_postgres::Error>,
+    f: impl Fn(T) -> serde_json::Value,
+) -> serde_json::Value {
+    match result {
+        Ok(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .map(|item| item.map(&f).unwrap_or(serde_json::Value::Null))
+                .collect(),
+        ),
+        Err(_) => serde_json::Value::Null,
+    }
+}
+
+/// Decode a single column value into a `serde_json::Value`, mapping common
+/// Postgres types to their natural JSON representation. Integers and floats
+/// become JSON numbers, `numeric` and temporal types are rendered as strings
+/// to preserve precision/formatting, `bytea` is base64-encoded, `json`/`jsonb`
+/// pass through as real JSON, and one-dimensional arrays of these map to JSON
+/// arrays. Any other type is read as a string where the driver can decode it
+/// as one (text-like types) and becomes JSON `null` otherwise.
+fn pg_value_to_json(
+    row: &tokio_postgres::Row,
+    idx: usize,
+    ty: &tokio_postgres::types::Type,
+) -> serde_json::Value {
+    use serde_json::Value;
+
+    let string = |s: String| Value::String(s);
+    let from_f64 = |v: f64| serde_json::Number::from_f64(v).map(Value::Number).unwrap_or(Value::Null);
+
+    match ty.name() {
+        "int2" => row
+            .try_get::<_, i16>(idx)
+            .map(|v| Value::Number(v.into()))
+            .unwrap_or(Value::Null),
+        "int4" => row
+            .try_get::<_, i32>(idx)
+            .map(|v| Value::Number(v.into()))
+            .unwrap_or(Value::Null),
+        "int8" => row
+            .try_get::<_, i64>(idx)
+            .map(|v| Value::Number(v.into()))
+            .unwrap_or(Value::Null),
+        "float4" => row.try_get::<_, f32>(idx).map(|v| from_f64(v as f64)).unwrap_or(Value::Null),
+        "float8" => row.try_get::<_, f64>(idx).map(from_f64).unwrap_or(Value::Null),
+        "numeric" => row
+            .try_get::<_, PgNumericText>(idx)
+            .map(|n| Value::String(n.0))
+            .unwrap_or(Value::Null),
+        "text" | "varchar" | "bpchar" | "name" => {
+            row.try_get::<_, String>(idx).map(string).unwrap_or(Value::Null)
+        }
+        "bool" => row.try_get::<_, bool>(idx).map(Value::Bool).unwrap_or(Value::Null),
+        "uuid" => row
+            .try_get::<_, uuid::Uuid>(idx)
+            .map(|u| Value::String(u.to_string()))
+            .unwrap_or(Value::Null),
+        "json" | "jsonb" => row.try_get::<_, Value>(idx).unwrap_or(Value::Null),
+        "bytea" => row
+            .try_get::<_, Vec<u8>>(idx)
+            .map(|b| Value::String(base64::engine::general_purpose::STANDARD.encode(b)))
+            .unwrap_or(Value::Null),
+        "date" => row
+            .try_get::<_, chrono::NaiveDate>(idx)
+            .map(|d| Value::String(d.to_string()))
+            .unwrap_or(Value::Null),
+        "time" => row
+            .try_get::<_, chrono::NaiveTime>(idx)
+            .map(|t| Value::String(t.to_string()))
+            .unwrap_or(Value::Null),
+        "timestamp" => row
+            .try_get::<_, chrono::NaiveDateTime>(idx)
+            .map(|t| Value::String(t.format("%Y-%m-%dT%H:%M:%S%.f").to_string()))
+            .unwrap_or(Value::Null),
+        "timestamptz" => row
+            .try_get::<_, chrono::DateTime<chrono::Utc>>(idx)
+            .map(|t| Value::String(t.to_rfc3339()))
+            .unwrap_or(Value::Null),
+        "_int2" => array_to_json(row.try_get::<_, Vec<Option<i16>>>(idx), |v| Value::Number(v.into())),
+        "_int4" => array_to_json(row.try_get::<_, Vec<Option<i32>>>(idx), |v| Value::Number(v.into())),
+        "_int8" => array_to_json(row.try_get::<_, Vec<Option<i64>>>(idx), |v| Value::Number(v.into())),
+        "_float4" => array_to_json(row.try_get::<_, Vec<Option<f32>>>(idx), move |v| from_f64(v as f64)),
+        "_float8" => array_to_json(row.try_get::<_, Vec<Option<f64>>>(idx), from_f64),
+        "_numeric" => array_to_json(row.try_get::<_, Vec<Option<PgNumericText>>>(idx), |n| {
+            Value::String(n.0)
+        }),
+        "_text" | "_varchar" | "_bpchar" => {
+            array_to_json(row.try_get::<_, Vec<Option<String>>>(idx), Value::String)
+        }
+        "_bool" => array_to_json(row.try_get::<_, Vec<Option<bool>>>(idx), Value::Bool),
+        "_uuid" => array_to_json(row.try_get::<_, Vec<Option<uuid::Uuid>>>(idx), |u| {
+            Value::String(u.to_string())
+        }),
+        "_timestamptz" => array_to_json(
+            row.try_get::<_, Vec<Option<chrono::DateTime<chrono::Utc>>>>(idx),
+            |t| Value::String(t.to_rfc3339()),
+        ),
+        "_timestamp" => array_to_json(
+            row.try_get::<_, Vec<Option<chrono::NaiveDateTime>>>(idx),
+            |t| Value::String(t.format("%Y-%m-%dT%H:%M:%S%.f").to_string()),
+        ),
+        "_date" => array_to_json(row.try_get::<_, Vec<Option<chrono::NaiveDate>>>(idx), |d| {
+            Value::String(d.to_string())
+        }),
+        "_time" => array_to_json(row.try_get::<_, Vec<Option<chrono::NaiveTime>>>(idx), |t| {
+            Value::String(t.to_string())
+        }),
+        "_json" | "_jsonb" => array_to_json(row.try_get::<_, Vec<Option<Value>>>(idx), |v| v),
+        "_bytea" => array_to_json(row.try_get::<_, Vec<Option<Vec<u8>>>>(idx), |b| {
+            Value::String(base64::engine::general_purpose::STANDARD.encode(b))
+        }),
+        _ => {
+            // Try to get as string for other types
+            row.try_get::<_, String>(idx).map(string).unwrap_or(Value::Null)
+        }
+    }
+}
+
+/// A single foreign-key relationship `table.column -> ref_table.ref_column`.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct ForeignKeyEdge {
+    table: String,
+    column: String,
+    ref_table: String,
+    ref_column: String,
+    /// The owning constraint, so multi-column (composite) foreign keys can be
+    /// regrouped into a single `ADD CONSTRAINT` instead of one per column pair.
+    constraint_name: String,
+}
+
+/// Fetch every foreign-key edge in the `public` schema in one pass.
+///
+/// Used to order tables for `export_dump` and to build the schema graph.
+async fn fetch_foreign_keys(
+    client: &tokio_postgres::Client,
+) -> Result<Vec<ForeignKeyEdge>, String> {
+    // Referenced columns are reached through `referential_constraints` and a
+    // second `key_column_usage` join matched on ordinal position. Joining
+    // `constraint_column_usage` directly on `constraint_name` instead produces
+    // an N×N cartesian product for composite keys, scrambling the column pairs.
+    let query = r#"
+        SELECT
+            tc.constraint_name AS constraint_name,
+            tc.table_name AS table_name,
+            kcu.column_name AS column_name,
+            rcu.table_name AS ref_table,
+            rcu.column_name AS ref_column
+        FROM information_schema.table_constraints AS tc
+        JOIN information_schema.key_column_usage AS kcu
+            ON tc.constraint_name = kcu.constraint_name
+            AND tc.table_schema = kcu.table_schema
+        JOIN information_schema.referential_constraints AS rc
+            ON rc.constraint_name = tc.constraint_name
+            AND rc.constraint_schema = tc.table_schema
+        JOIN information_schema.key_column_usage AS rcu
+            ON rcu.constraint_name = rc.unique_constraint_name
+            AND rcu.constraint_schema = rc.unique_constraint_schema
+            AND rcu.ordinal_position = kcu.position_in_unique_constraint
+        WHERE tc.constraint_type = 'FOREIGN KEY'
+            AND tc.table_schema = 'public'
+        ORDER BY tc.table_name, tc.constraint_name, kcu.ordinal_position;
+    "#;
+
+    let rows = client.query(query, &[]).await.map_err(|e| e.to_string())?;
+    Ok(rows
+        .iter()
+        .map(|row| ForeignKeyEdge {
+            table: row.get("table_name"),
+            column: row.get("column_name"),
+            ref_table: row.get("ref_table"),
+            ref_column: row.get("ref_column"),
+            constraint_name: row.get("constraint_name"),
+        })
+        .collect())
+}
+
+/// Fetch the names of every base table in the `public` schema.
+async fn fetch_table_names(client: &tokio_postgres::Client) -> Result<Vec<String>, String> {
+    let rows = client
+        .query(
+            "SELECT table_name FROM information_schema.tables
+             WHERE table_schema = 'public'
+             AND table_type = 'BASE TABLE'
+             ORDER BY table_name;",
+            &[],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(rows.iter().map(|row| row.get("table_name")).collect())
+}
+
+/// Quote an SQL identifier by wrapping it in double quotes and doubling any
+/// embedded quote, so arbitrary table/column names survive the dump.
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Order `tables` so that referenced (parent) tables precede the tables that
+/// reference them, using Kahn's algorithm. Self-references are ignored for
+/// ordering; if a cycle remains its tables are appended in their original
+/// order (their FK constraints are emitted separately at the end of the dump).
+fn topo_sort_tables(tables: &[String], edges: &[ForeignKeyEdge]) -> Vec<String> {
+    use std::collections::{HashSet, VecDeque};
+
+    let present: HashSet<&str> = tables.iter().map(String::as_str).collect();
+
+    // Unique (child, parent) dependency pairs within the selected tables.
+    let mut deps: HashSet<(&str, &str)> = HashSet::new();
+    for edge in edges {
+        if edge.table == edge.ref_table {
+            continue;
+        }
+        if present.contains(edge.table.as_str()) && present.contains(edge.ref_table.as_str()) {
+            deps.insert((edge.table.as_str(), edge.ref_table.as_str()));
+        }
+    }
+
+    let mut in_degree: HashMap<&str, usize> =
+        tables.iter().map(|t| (t.as_str(), 0usize)).collect();
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (child, parent) in &deps {
+        *in_degree.entry(child).or_insert(0) += 1;
+        children.entry(parent).or_default().push(child);
+    }
+
+    let mut queue: VecDeque<&str> = tables
+        .iter()
+        .map(String::as_str)
+        .filter(|t| in_degree.get(t).copied().unwrap_or(0) == 0)
+        .collect();
+
+    let mut order: Vec<String> = Vec::with_capacity(tables.len());
+    while let Some(table) = queue.pop_front() {
+        order.push(table.to_string());
+        if let Some(kids) = children.get(table) {
+            for kid in kids {
+                let degree = in_degree.get_mut(kid).expect("child tracked in in_degree");
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(kid);
+                }
+            }
+        }
+    }
+
+    // Any table left out was part of a cycle; append it so the dump is complete.
+    for table in tables {
+        if !order.iter().any(|t| t == table) {
+            order.push(table.clone());
+        }
+    }
+    order
+}
+
+/// Whether a column is a `GENERATED ALWAYS AS IDENTITY` column, whose value is
+/// assigned by the system and cannot appear in an `INSERT` column list. `BY
+/// DEFAULT` identity columns return `false`: their values must be dumped so a
+/// restore keeps the keys that other rows' foreign keys point at.
+fn is_always_identity(col: &ColumnInfo) -> bool {
+    col.is_identity && col.identity_generation.as_deref() == Some("ALWAYS")
+}
+
+/// Render a single `CREATE TABLE` statement from column metadata, honoring
+/// nullability, defaults, generated/identity columns and the primary key.
+///
+/// The column type comes from `format_type` (so lengths, precision, arrays and
+/// user-defined types render correctly), identity columns keep their catalog
+/// `ALWAYS`/`BY DEFAULT` flavor, and `serial`/`nextval(...)` defaults are
+/// rewritten to `GENERATED BY DEFAULT AS IDENTITY` so the statement restores
+/// without a separately-created backing sequence.
+fn render_create_table(table: &str, columns: &[ColumnInfo]) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for col in columns {
+        let mut def = format!("    {} {}", quote_ident(&col.name), col.formatted_type);
+        if col.is_identity {
+            if col.identity_generation.as_deref() == Some("ALWAYS") {
+                def.push_str(" GENERATED ALWAYS AS IDENTITY");
+            } else {
+                def.push_str(" GENERATED BY DEFAULT AS IDENTITY");
+            }
+        } else if col.is_generated {
+            if let Some(expr) = &col.generation_expression {
+                def.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", expr));
+            }
+        } else if col.is_auto_generated {
+            def.push_str(" GENERATED BY DEFAULT AS IDENTITY");
+        } else if let Some(default) = &col.column_default {
+            def.push_str(&format!(" DEFAULT {}", default));
+        }
+        if !col.is_nullable {
+            def.push_str(" NOT NULL");
+        }
+        lines.push(def);
+    }
+
+    let pk: Vec<String> = columns
+        .iter()
+        .filter(|c| c.is_primary_key)
+        .map(|c| quote_ident(&c.name))
+        .collect();
+    if !pk.is_empty() {
+        lines.push(format!("    PRIMARY KEY ({})", pk.join(", ")));
+    }
+
+    format!("CREATE TABLE {} (\n{}\n);\n", quote_ident(table), lines.join(",\n"))
+}
+
+/// Render the `ALTER TABLE ... ADD CONSTRAINT ... FOREIGN KEY` statements for a
+/// table, restricted to edges whose referenced table is also being dumped.
+fn render_foreign_keys(table: &str, edges: &[ForeignKeyEdge], present: &[String]) -> String {
+    use std::collections::BTreeMap;
+
+    // Group this table's edges by owning constraint so a composite foreign key
+    // renders as one multi-column `ADD CONSTRAINT`, keeping the column and
+    // referenced-column lists in the order the query returned them.
+    let mut by_constraint: BTreeMap<&str, (&str, Vec<&str>, Vec<&str>)> = BTreeMap::new();
+    for edge in edges.iter().filter(|e| e.table == table) {
+        if !present.iter().any(|t| t == &edge.ref_table) {
+            continue;
+        }
+        let entry = by_constraint
+            .entry(&edge.constraint_name)
+            .or_insert((&edge.ref_table, Vec::new(), Vec::new()));
+        entry.1.push(&edge.column);
+        entry.2.push(&edge.ref_column);
+    }
+
+    let mut out = String::new();
+    for (name, (ref_table, columns, ref_columns)) in by_constraint {
+        let local: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+        let foreign: Vec<String> = ref_columns.iter().map(|c| quote_ident(c)).collect();
+        out.push_str(&format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({});\n",
+            quote_ident(table),
+            quote_ident(name),
+            local.join(", "),
+            quote_ident(ref_table),
+            foreign.join(", "),
+        ));
+    }
+    out
+}
+
+/// Render `INSERT INTO` statements for a table's rows, quoting every value as
+/// an escaped SQL literal. Stored-generated and `GENERATED ALWAYS` identity
+/// columns are skipped (they cannot be inserted), but `BY DEFAULT` identity and
+/// `serial` keys are included so the original key values — and the foreign keys
+/// that reference them — survive a restore.
+async fn render_inserts(
+    client: &tokio_postgres::Client,
+    table: &str,
+    columns: &[ColumnInfo],
+) -> Result<String, String> {
+    let cols: Vec<&ColumnInfo> = columns
+        .iter()
+        .filter(|c| !c.is_generated && !is_always_identity(c))
+        .collect();
+    if cols.is_empty() {
+        return Ok(String::new());
+    }
+
+    // Cast every column to text so arbitrary types serialize uniformly; the
+    // values are re-quoted as string literals and coerced back on INSERT.
+    let select_list = cols
+        .iter()
+        .map(|c| format!("{}::text", quote_ident(&c.name)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("SELECT {} FROM {}", select_list, quote_ident(table));
+    let rows = client.query(&query, &[]).await.map_err(|e| e.to_string())?;
+
+    let col_list = cols
+        .iter()
+        .map(|c| quote_ident(&c.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut out = String::new();
+    for row in &rows {
+        let values: Vec<String> = (0..cols.len())
+            .map(|i| match row.try_get::<_, Option<String>>(i) {
+                Ok(Some(s)) => format!("'{}'", s.replace('\'', "''")),
+                _ => "NULL".to_string(),
+            })
+            .collect();
+        out.push_str(&format!(
+            "INSERT INTO {} ({}) VALUES ({});\n",
+            quote_ident(table),
+            col_list,
+            values.join(", ")
+        ));
+    }
+    Ok(out)
+}
+
+/// Produce an executable SQL dump: `CREATE TABLE` statements in dependency
+/// order, optional `INSERT` data, then all foreign-key constraints at the end
+/// (which keeps cyclic/self-referential FKs valid).
+#[tauri::command]
+async fn export_dump(
+    connection_string: String,
+    tables: Option<Vec<String>>,
+    include_data: bool,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<String, String> {
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default()).await?;
+
+    let tables = match tables {
+        Some(t) if !t.is_empty() => t,
+        _ => fetch_table_names(&client).await?,
+    };
+    let edges = fetch_foreign_keys(&client).await?;
+    let ordered = topo_sort_tables(&tables, &edges);
+
+    let mut schema = String::new();
+    let mut data = String::new();
+    let mut constraints = String::new();
+
+    for table in &ordered {
+        let columns = fetch_columns(&client, table).await?;
+        schema.push_str(&render_create_table(table, &columns));
+        schema.push('\n');
+        constraints.push_str(&render_foreign_keys(table, &edges, &ordered));
+        if include_data {
+            data.push_str(&render_inserts(&client, table, &columns).await?);
+        }
+    }
+
+    let mut dump = String::new();
+    dump.push_str("-- Postgres-dumper export\n\n");
+    dump.push_str(&schema);
+    if include_data && !data.is_empty() {
+        dump.push('\n');
+        dump.push_str(&data);
+    }
+    if !constraints.is_empty() {
+        dump.push('\n');
+        dump.push_str(&constraints);
+    }
+
+    println!("[Rust export_dump] Dumped {} tables", ordered.len());
+    Ok(dump)
+}
+
+/// A table node in the schema graph, carrying its column list.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct TableNode {
+    table: String,
+    columns: Vec<ColumnInfo>,
+}
+
+/// The whole-database relationship graph: table nodes plus directed
+/// foreign-key edges, suitable for rendering an ER diagram or picking an
+/// export order without per-table round trips.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct SchemaGraph {
+    nodes: Vec<TableNode>,
+    edges: Vec<ForeignKeyEdge>,
+}
+
+#[tauri::command]
+async fn get_schema_graph(
+    connection_string: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+) -> Result<SchemaGraph, String> {
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default())
+        .await
+        .map_err(|e| {
+            eprintln!("[Rust get_schema_graph] Connection failed: {}", e);
+            e
+        })?;
+
+    let tables = fetch_table_names(&client).await?;
+    let mut nodes = Vec::with_capacity(tables.len());
+    for table in &tables {
+        let columns = fetch_columns(&client, table).await?;
+        nodes.push(TableNode {
+            table: table.clone(),
+            columns,
+        });
+    }
+
+    let edges = fetch_foreign_keys(&client).await?;
+
+    println!(
+        "[Rust get_schema_graph] Built graph with {} tables and {} edges",
+        nodes.len(),
+        edges.len()
+    );
+
+    Ok(SchemaGraph { nodes, edges })
+}
+
+/// Progress payload emitted while `export_table` streams a table to disk.
+#[derive(serde::Serialize, Clone)]
+pub struct ExportProgress {
+    bytes_written: u64,
+    estimated_rows: i64,
+}
+
+/// Stream a whole table to a file via `COPY ... TO STDOUT`, writing chunks
+/// straight to disk so memory stays flat regardless of table size.
+///
+/// `format` selects `csv` (with a header row) or `ndjson` (one
+/// `row_to_json` object per line). `export_progress` events carry the running
+/// byte count and an estimated row total so the frontend can show a progress
+/// bar. Returns the total number of bytes written.
+#[tauri::command]
+async fn export_table(
+    connection_string: String,
+    table: String,
+    format: String,
+    path: String,
+    tls: Option<TlsOptions>,
+    state: tauri::State<'_, DbState>,
+    window: tauri::Window,
+) -> Result<u64, String> {
+    use futures::StreamExt;
+    use tauri::Emitter;
+    use tokio::io::AsyncWriteExt;
+
+    let client = get_client(&state, &connection_string, &tls.unwrap_or_default()).await?;
+
+    let copy_sql = match format.as_str() {
+        "csv" => format!(
+            "COPY (SELECT * FROM {}) TO STDOUT WITH (FORMAT csv, HEADER true)",
+            quote_ident(&table)
+        ),
+        // COPY's default text format backslash-escapes the JSON that
+        // `row_to_json` already escaped, double-escaping any backslash or
+        // control character. CSV format does no backslash escaping; picking a
+        // QUOTE/DELIMITER that never occur in JSON (row_to_json escapes all
+        // control chars, so the text is single-line and quote-free) means the
+        // single column is emitted raw and the NDJSON round-trips.
+        "ndjson" => format!(
+            "COPY (SELECT row_to_json(t) FROM {} t) TO STDOUT \
+             WITH (FORMAT csv, QUOTE E'\\x01', DELIMITER E'\\x02')",
+            quote_ident(&table)
+        ),
+        other => return Err(format!("unsupported export format: {}", other)),
+    };
+
+    // Best-effort row estimate (from planner statistics) for the progress bar.
+    // Resolve the table through `to_regclass` so a bare name shared across
+    // schemas can't match multiple `pg_class` rows (which would make
+    // `query_opt` error), and never let a failed estimate abort the export.
+    let qualified = format!("public.{}", quote_ident(&table));
+    let estimated_rows: i64 = client
+        .query_opt(
+            "SELECT reltuples::bigint FROM pg_class \
+             WHERE oid = to_regclass($1) AND relkind = 'r'",
+            &[&qualified],
+        )
+        .await
+        .ok()
+        .flatten()
+        .map(|row| row.get(0))
+        .unwrap_or(0);
+
+    let mut file = tokio::fs::File::create(&path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let stream = client.copy_out(&copy_sql).await.map_err(|e| e.to_string())?;
+    futures::pin_mut!(stream);
+
+    let mut bytes_written: u64 = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        file.write_all(&chunk).await.map_err(|e| e.to_string())?;
+        bytes_written += chunk.len() as u64;
+        let _ = window.emit(
+            "export_progress",
+            ExportProgress {
+                bytes_written,
+                estimated_rows,
+            },
+        );
+    }
+    file.flush().await.map_err(|e| e.to_string())?;
+
+    println!(
+        "[Rust export_table] Wrote {} bytes to {}",
+        bytes_written, path
+    );
+    Ok(bytes_written)
+}
+
+/// Drop every pool for `connection_string` (across all TLS configurations) so
+/// the UI can tear down idle connections. A no-op when none exist.
+#[tauri::command]
+async fn disconnect(
+    connection_string: String,
+    state: tauri::State<'_, DbState>,
+) -> Result<(), String> {
+    let mut pools = state.pools.lock().map_err(|e| e.to_string())?;
+    let prefix = format!("{}|", connection_string);
+    let keys: Vec<String> = pools
+        .keys()
+        .filter(|k| k.as_str() == connection_string || k.starts_with(&prefix))
+        .cloned()
+        .collect();
+    for key in keys {
+        if let Some(pool) = pools.remove(&key) {
+            pool.close();
+            println!("[Rust disconnect] Closed pool for connection");
+        }
+    }
+    Ok(())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(DbState::default())
         .invoke_handler(tauri::generate_handler![
             greet,
             test_connection,
             execute_sql,
             get_tables,
             get_columns,
-            execute_query
+            execute_query,
+            export_dump,
+            export_table,
+            get_schema_graph,
+            disconnect
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");